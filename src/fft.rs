@@ -0,0 +1,274 @@
+use num_complex::Complex;
+use std::f64::consts::PI;
+
+/// Selects which radix-2 Cooley-Tukey recursion `fft_with_algorithm` uses.
+///
+/// Both variants compute the same spectrum; they differ in whether the
+/// even/odd split happens before recursing (decimation-in-time) or the
+/// butterfly combine happens before recursing (decimation-in-frequency).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FftAlgorithm {
+    DecimationInTime,
+    DecimationInFrequency,
+}
+
+/// Computes the discrete Fourier transform of a complex sequence using the
+/// radix-2 Cooley-Tukey decimation-in-time algorithm.
+///
+/// Inputs whose length is not a power of two are zero-padded up to the next
+/// power of two before the transform is applied.
+///
+/// # Arguments
+/// * `input` - The complex-valued time-domain sequence.
+///
+/// # Returns
+/// * `Vec<Complex<f64>>` - The complex-valued frequency-domain sequence.
+///
+/// # Examples
+/// ```
+/// use num_complex::Complex;
+/// use rusp::fft::fft;
+///
+/// let input = vec![
+///     Complex::new(1.0, 0.0),
+///     Complex::new(2.0, 0.0),
+///     Complex::new(3.0, 0.0),
+///     Complex::new(4.0, 0.0),
+/// ];
+/// let spectrum = fft(&input);
+/// assert_eq!(spectrum.len(), 4);
+/// ```
+pub fn fft(input: &[Complex<f64>]) -> Vec<Complex<f64>> {
+    fft_with_algorithm(input, FftAlgorithm::DecimationInTime)
+}
+
+/// Computes the discrete Fourier transform using the requested
+/// [`FftAlgorithm`] variant.
+///
+/// # Arguments
+/// * `input` - The complex-valued time-domain sequence.
+/// * `algorithm` - Which radix-2 recursion to use.
+///
+/// # Returns
+/// * `Vec<Complex<f64>>` - The complex-valued frequency-domain sequence.
+pub fn fft_with_algorithm(input: &[Complex<f64>], algorithm: FftAlgorithm) -> Vec<Complex<f64>> {
+    let padded = pad_to_next_pow2(input);
+    match algorithm {
+        FftAlgorithm::DecimationInTime => fft_dit(&padded),
+        FftAlgorithm::DecimationInFrequency => fft_dif(&padded),
+    }
+}
+
+/// Computes the inverse discrete Fourier transform of a complex sequence.
+///
+/// Implemented via the standard trick of conjugating the input, running the
+/// forward transform, conjugating the result, and dividing by `N`.
+///
+/// # Arguments
+/// * `input` - The complex-valued frequency-domain sequence.
+///
+/// # Returns
+/// * `Vec<Complex<f64>>` - The complex-valued time-domain sequence.
+///
+/// # Examples
+/// ```
+/// use num_complex::Complex;
+/// use rusp::fft::{fft, ifft};
+///
+/// let input = vec![
+///     Complex::new(1.0, 0.0),
+///     Complex::new(2.0, 0.0),
+///     Complex::new(3.0, 0.0),
+///     Complex::new(4.0, 0.0),
+/// ];
+/// let restored = ifft(&fft(&input));
+/// assert!((restored[0] - input[0]).norm() < 1e-9);
+/// ```
+pub fn ifft(input: &[Complex<f64>]) -> Vec<Complex<f64>> {
+    let conjugated: Vec<Complex<f64>> = input.iter().map(|c| c.conj()).collect();
+    let transformed = fft(&conjugated);
+    let n = transformed.len() as f64;
+    transformed.iter().map(|c| c.conj() / n).collect()
+}
+
+/// Convenience wrapper around [`fft`] for real-valued input.
+///
+/// # Arguments
+/// * `input` - The real-valued time-domain sequence.
+///
+/// # Returns
+/// * `Vec<Complex<f64>>` - The complex-valued frequency-domain sequence.
+pub fn rfft(input: &[f64]) -> Vec<Complex<f64>> {
+    let complex_input: Vec<Complex<f64>> = input.iter().map(|&x| Complex::new(x, 0.0)).collect();
+    fft(&complex_input)
+}
+
+/// Convenience wrapper around [`ifft`] that discards the (negligible)
+/// imaginary part of the restored time-domain sequence.
+///
+/// # Arguments
+/// * `input` - The complex-valued frequency-domain sequence.
+///
+/// # Returns
+/// * `Vec<f64>` - The real-valued time-domain sequence.
+pub fn irfft(input: &[Complex<f64>]) -> Vec<f64> {
+    ifft(input).iter().map(|c| c.re).collect()
+}
+
+/// Zero-pads `input` up to the smallest power of two greater than or equal
+/// to its length.
+fn pad_to_next_pow2(input: &[Complex<f64>]) -> Vec<Complex<f64>> {
+    let target = next_pow2(input.len());
+    let mut padded = input.to_vec();
+    padded.resize(target, Complex::new(0.0, 0.0));
+    padded
+}
+
+/// Returns the smallest power of two greater than or equal to `n`.
+fn next_pow2(n: usize) -> usize {
+    if n == 0 {
+        return 1;
+    }
+    let mut power = 1;
+    while power < n {
+        power <<= 1;
+    }
+    power
+}
+
+/// Decimation-in-time radix-2 Cooley-Tukey recursion: split into even/odd
+/// subsequences, recurse, then recombine with twiddle factors.
+fn fft_dit(input: &[Complex<f64>]) -> Vec<Complex<f64>> {
+    let n = input.len();
+    if n <= 1 {
+        return input.to_vec();
+    }
+
+    let even: Vec<Complex<f64>> = input.iter().step_by(2).copied().collect();
+    let odd: Vec<Complex<f64>> = input.iter().skip(1).step_by(2).copied().collect();
+
+    let even_fft = fft_dit(&even);
+    let odd_fft = fft_dit(&odd);
+
+    let mut output = vec![Complex::new(0.0, 0.0); n];
+    let half = n / 2;
+    for k in 0..half {
+        let twiddle = Complex::from_polar(1.0, -2.0 * PI * k as f64 / n as f64);
+        let term = twiddle * odd_fft[k];
+        output[k] = even_fft[k] + term;
+        output[k + half] = even_fft[k] - term;
+    }
+    output
+}
+
+/// Decimation-in-frequency radix-2 Cooley-Tukey recursion: butterfly-combine
+/// into two half-length sequences, then recurse.
+fn fft_dif(input: &[Complex<f64>]) -> Vec<Complex<f64>> {
+    let n = input.len();
+    if n <= 1 {
+        return input.to_vec();
+    }
+
+    let half = n / 2;
+    let mut sums = vec![Complex::new(0.0, 0.0); half];
+    let mut diffs = vec![Complex::new(0.0, 0.0); half];
+    for k in 0..half {
+        let twiddle = Complex::from_polar(1.0, -2.0 * PI * k as f64 / n as f64);
+        sums[k] = input[k] + input[k + half];
+        diffs[k] = (input[k] - input[k + half]) * twiddle;
+    }
+
+    let sums_fft = fft_dif(&sums);
+    let diffs_fft = fft_dif(&diffs);
+
+    let mut output = vec![Complex::new(0.0, 0.0); n];
+    for k in 0..half {
+        output[2 * k] = sums_fft[k];
+        output[2 * k + 1] = diffs_fft[k];
+    }
+    output
+}
+
+#[cfg(test)]
+mod fft_tests {
+    use super::*;
+
+    fn dft(input: &[Complex<f64>]) -> Vec<Complex<f64>> {
+        let n = input.len();
+        let mut output = vec![Complex::new(0.0, 0.0); n];
+        for (k, slot) in output.iter_mut().enumerate() {
+            let mut sum = Complex::new(0.0, 0.0);
+            for (t, &x) in input.iter().enumerate() {
+                let angle = -2.0 * PI * (k * t) as f64 / n as f64;
+                sum += x * Complex::from_polar(1.0, angle);
+            }
+            *slot = sum;
+        }
+        output
+    }
+
+    fn assert_complex_close(a: &[Complex<f64>], b: &[Complex<f64>]) {
+        assert_eq!(a.len(), b.len());
+        for (x, y) in a.iter().zip(b.iter()) {
+            assert!((x - y).norm() < 1e-9, "{:?} != {:?}", x, y);
+        }
+    }
+
+    #[test]
+    fn test_fft_matches_dft_power_of_two() {
+        let input = vec![
+            Complex::new(1.0, 0.0),
+            Complex::new(2.0, 0.0),
+            Complex::new(3.0, 0.0),
+            Complex::new(4.0, 0.0),
+        ];
+        assert_complex_close(&fft(&input), &dft(&input));
+    }
+
+    #[test]
+    fn test_fft_pads_non_power_of_two_input() {
+        let input = vec![
+            Complex::new(1.0, 0.0),
+            Complex::new(2.0, 0.0),
+            Complex::new(3.0, 0.0),
+        ];
+        let mut padded = input.clone();
+        padded.push(Complex::new(0.0, 0.0));
+
+        assert_complex_close(&fft(&input), &dft(&padded));
+    }
+
+    #[test]
+    fn test_fft_dif_matches_dit() {
+        let input = vec![
+            Complex::new(1.0, 0.0),
+            Complex::new(2.0, -1.0),
+            Complex::new(0.0, 3.0),
+            Complex::new(-1.0, 0.0),
+        ];
+        let dit = fft_with_algorithm(&input, FftAlgorithm::DecimationInTime);
+        let dif = fft_with_algorithm(&input, FftAlgorithm::DecimationInFrequency);
+        assert_complex_close(&dit, &dif);
+    }
+
+    #[test]
+    fn test_ifft_roundtrip() {
+        let input = vec![
+            Complex::new(1.0, 0.0),
+            Complex::new(2.0, -1.0),
+            Complex::new(0.0, 3.0),
+            Complex::new(-1.0, 0.0),
+        ];
+        let restored = ifft(&fft(&input));
+        assert_complex_close(&restored, &input);
+    }
+
+    #[test]
+    fn test_rfft_irfft_roundtrip() {
+        let input = vec![1.0, 2.0, 3.0, 4.0];
+        let restored = irfft(&rfft(&input));
+        for (a, b) in restored.iter().zip(input.iter()) {
+            assert!((a - b).abs() < 1e-9);
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod fft;
+pub mod misc;
@@ -1,8 +1,73 @@
+use num_complex::Complex;
 use num_traits::{Num, NumCast, ToPrimitive};
 
-/// Generates a vector of evenly spaced numbers over a specified interval.
+/// A lazy, double-ended, exact-size iterator over evenly spaced `f64` values.
 ///
-/// This is equivalent to the NumPy [`linspace()`](https://numpy.org/doc/stable/reference/generated/numpy.linspace.html) function.
+/// Returned by [`linspace_iter`]. Each value is computed as `start + index *
+/// step` rather than by repeated addition, so forward and backward iteration
+/// produce identical values and error does not accumulate. When `include_end`
+/// is set, the final index yields exactly `stop`.
+#[derive(Debug, Clone)]
+pub struct Linspace {
+    start: f64,
+    step: f64,
+    stop: f64,
+    include_end: bool,
+    samples: usize,
+    front: usize,
+    back: usize,
+}
+
+impl Linspace {
+    fn value_at(&self, index: usize) -> f64 {
+        if self.include_end && index == self.samples - 1 {
+            self.stop
+        } else {
+            self.start + index as f64 * self.step
+        }
+    }
+}
+
+impl Iterator for Linspace {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<f64> {
+        if self.front >= self.back {
+            return None;
+        }
+        let value = self.value_at(self.front);
+        self.front += 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl DoubleEndedIterator for Linspace {
+    fn next_back(&mut self) -> Option<f64> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.value_at(self.back))
+    }
+}
+
+impl ExactSizeIterator for Linspace {
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
+}
+
+/// Returns a lazy iterator over evenly spaced numbers over a specified interval.
+///
+/// This mirrors the NumPy [`linspace()`](https://numpy.org/doc/stable/reference/generated/numpy.linspace.html)
+/// function, but instead of eagerly allocating a `Vec<f64>`, it yields values on
+/// demand like `Range` or `step_by`. The returned [`Linspace`] also supports
+/// reverse iteration via [`DoubleEndedIterator`].
 ///
 /// # Arguments
 /// * `start` - Start value of the sequence (supports `i32`, `i64`, `f32`, `f64`).
@@ -11,23 +76,22 @@ use num_traits::{Num, NumCast, ToPrimitive};
 /// * `include_end` - Whether to include `stop` in the sequence.
 ///
 /// # Returns
-/// A `Vec<f64>` containing evenly spaced values.
+/// A [`Linspace`] iterator yielding evenly spaced `f64` values.
 ///
 /// # Examples
 /// ```
-/// use rusp::misc::utils::linspace;
+/// use rusp::misc::utils::linspace_iter;
 ///
-/// let result = linspace(0, 10, 5, true);
+/// let result: Vec<f64> = linspace_iter(0, 10, 5, true).collect();
 /// assert_eq!(result, vec![0.0, 2.5, 5.0, 7.5, 10.0]);
+///
+/// let reversed: Vec<f64> = linspace_iter(0, 10, 5, true).rev().collect();
+/// assert_eq!(reversed, vec![10.0, 7.5, 5.0, 2.5, 0.0]);
 /// ```
-pub fn linspace<T>(start: T, stop: T, samples: usize, include_end: bool) -> Vec<f64>
+pub fn linspace_iter<T>(start: T, stop: T, samples: usize, include_end: bool) -> Linspace
 where
     T: NumCast + Copy + PartialOrd + ToPrimitive,
 {
-    if samples == 0 {
-        return Vec::new();
-    }
-
     let start_f = start.to_f64().unwrap();
     let stop_f = stop.to_f64().unwrap();
     let span = stop_f - start_f;
@@ -38,19 +102,156 @@ where
         span / (samples as f64)
     };
 
-    let mut values = Vec::with_capacity(samples);
-    let mut current = start_f;
+    Linspace {
+        start: start_f,
+        step,
+        stop: stop_f,
+        include_end,
+        samples,
+        front: 0,
+        back: samples,
+    }
+}
 
-    for _ in 0..samples {
-        values.push(current);
-        current += step;
+/// Generates a vector of evenly spaced numbers over a specified interval.
+///
+/// This is equivalent to the NumPy [`linspace()`](https://numpy.org/doc/stable/reference/generated/numpy.linspace.html) function.
+///
+/// # Arguments
+/// * `start` - Start value of the sequence (supports `i32`, `i64`, `f32`, `f64`).
+/// * `stop` - Stop value of the sequence.
+/// * `samples` - Number of samples to be generated.
+/// * `include_end` - Whether to include `stop` in the sequence.
+///
+/// # Returns
+/// A `Vec<f64>` containing evenly spaced values.
+///
+/// # Examples
+/// ```
+/// use rusp::misc::utils::linspace;
+///
+/// let result = linspace(0, 10, 5, true);
+/// assert_eq!(result, vec![0.0, 2.5, 5.0, 7.5, 10.0]);
+/// ```
+pub fn linspace<T>(start: T, stop: T, samples: usize, include_end: bool) -> Vec<f64>
+where
+    T: NumCast + Copy + PartialOrd + ToPrimitive,
+{
+    linspace_iter(start, stop, samples, include_end).collect()
+}
+
+/// Generates a vector of log-scaled numbers over a specified interval.
+///
+/// This is equivalent to the NumPy [`logspace()`](https://numpy.org/doc/stable/reference/generated/numpy.logspace.html)
+/// function: it runs [`linspace`] over the exponents and raises `base` to each
+/// resulting value.
+///
+/// # Arguments
+/// * `start` - Start exponent of the sequence.
+/// * `stop` - Stop exponent of the sequence.
+/// * `samples` - Number of samples to be generated.
+/// * `base` - The base to raise each exponent to.
+/// * `include_end` - Whether to include `stop` in the sequence of exponents.
+///
+/// # Returns
+/// A `Vec<f64>` containing log-scaled values.
+///
+/// # Examples
+/// ```
+/// use rusp::misc::utils::logspace;
+///
+/// let result = logspace(0.0, 2.0, 3, 10.0, true);
+/// assert_eq!(result, vec![1.0, 10.0, 100.0]);
+/// ```
+pub fn logspace(start: f64, stop: f64, samples: usize, base: f64, include_end: bool) -> Vec<f64> {
+    linspace(start, stop, samples, include_end)
+        .into_iter()
+        .map(|exponent| base.powf(exponent))
+        .collect()
+}
+
+/// Generates a vector of numbers evenly spaced on a log scale between two
+/// positive endpoints.
+///
+/// This is equivalent to the NumPy [`geomspace()`](https://numpy.org/doc/stable/reference/generated/numpy.geomspace.html)
+/// function. It reuses the [`linspace`] core directly by spacing the natural
+/// logarithms of `start` and `stop` evenly, then exponentiating.
+///
+/// # Arguments
+/// * `start` - Start value of the sequence (must be strictly positive).
+/// * `stop` - Stop value of the sequence (must be strictly positive).
+/// * `samples` - Number of samples to be generated.
+/// * `include_end` - Whether to include `stop` in the sequence.
+///
+/// # Returns
+/// A `Vec<f64>` containing log-spaced values.
+///
+/// # Panics
+/// Panics if `start` or `stop` is not strictly positive.
+///
+/// # Examples
+/// ```
+/// use rusp::misc::utils::geomspace;
+///
+/// let result = geomspace(1.0, 1000.0, 4, true);
+/// for (got, want) in result.iter().zip([1.0, 10.0, 100.0, 1000.0]) {
+///     assert!((got - want).abs() < 1e-9);
+/// }
+/// ```
+pub fn geomspace(start: f64, stop: f64, samples: usize, include_end: bool) -> Vec<f64> {
+    assert!(
+        start > 0.0 && stop > 0.0,
+        "geomspace requires strictly positive start and stop values"
+    );
+
+    linspace(start.ln(), stop.ln(), samples, include_end)
+        .into_iter()
+        .map(|exponent| exponent.exp())
+        .collect()
+}
+
+#[cfg(test)]
+mod logspace_geomspace_tests {
+    use super::{geomspace, logspace};
+
+    #[test]
+    fn test_logspace_inclusive() {
+        let result = logspace(0.0, 2.0, 3, 10.0, true);
+        assert_eq!(result, vec![1.0, 10.0, 100.0]);
+    }
+
+    #[test]
+    fn test_logspace_exclusive() {
+        let result = logspace(0.0, 2.0, 2, 10.0, false);
+        assert_eq!(result, vec![1.0, 10.0]);
+    }
+
+    #[test]
+    fn test_logspace_base_two() {
+        let result = logspace(0.0, 3.0, 4, 2.0, true);
+        assert_eq!(result, vec![1.0, 2.0, 4.0, 8.0]);
     }
 
-    if include_end {
-        *values.last_mut().unwrap() = stop_f;
+    #[test]
+    fn test_geomspace_inclusive() {
+        let result = geomspace(1.0, 1000.0, 4, true);
+        let expected = [1.0, 10.0, 100.0, 1000.0];
+        for (got, want) in result.iter().zip(expected.iter()) {
+            assert!((got - want).abs() < 1e-9, "{got} != {want}");
+        }
     }
 
-    values
+    #[test]
+    #[should_panic(expected = "geomspace requires strictly positive start and stop values")]
+    fn test_geomspace_rejects_non_positive_start() {
+        geomspace(0.0, 10.0, 5, true);
+    }
+
+    #[test]
+    #[should_panic(expected = "geomspace requires strictly positive start and stop values")]
+    fn test_geomspace_rejects_negative_stop() {
+        geomspace(1.0, -10.0, 5, true);
+    }
 }
 
 #[cfg(test)]
@@ -102,6 +303,63 @@ mod linspace_tests {
     }
 }
 
+#[cfg(test)]
+mod linspace_iter_tests {
+    use super::linspace_iter;
+
+    #[test]
+    fn test_linspace_iter_matches_eager_version() {
+        let result: Vec<f64> = linspace_iter(0, 10, 5, true).collect();
+        assert_eq!(result, vec![0.0, 2.5, 5.0, 7.5, 10.0]);
+    }
+
+    #[test]
+    fn test_linspace_iter_is_exact_size() {
+        let iter = linspace_iter(0, 10, 5, true);
+        assert_eq!(iter.len(), 5);
+        assert_eq!(iter.size_hint(), (5, Some(5)));
+    }
+
+    #[test]
+    fn test_linspace_iter_len_shrinks_as_consumed() {
+        let mut iter = linspace_iter(0, 10, 5, true);
+        iter.next();
+        iter.next();
+        assert_eq!(iter.len(), 3);
+    }
+
+    #[test]
+    fn test_linspace_iter_reverse_matches_forward_reversed() {
+        let forward: Vec<f64> = linspace_iter(0, 10, 5, true).collect();
+        let backward: Vec<f64> = linspace_iter(0, 10, 5, true).rev().collect();
+        let mut expected = forward.clone();
+        expected.reverse();
+        assert_eq!(backward, expected);
+    }
+
+    #[test]
+    fn test_linspace_iter_meet_in_middle() {
+        let mut iter = linspace_iter(0, 10, 5, true);
+        assert_eq!(iter.next(), Some(0.0));
+        assert_eq!(iter.next_back(), Some(10.0));
+        assert_eq!(iter.next(), Some(2.5));
+        assert_eq!(iter.next_back(), Some(7.5));
+        assert_eq!(iter.next(), Some(5.0));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_linspace_iter_include_end_exact_on_both_ends() {
+        let mut iter = linspace_iter(0, 7, 6, true);
+        let last = iter.next_back();
+        assert_eq!(last, Some(7.0));
+
+        let forward: Vec<f64> = linspace_iter(0, 7, 6, true).collect();
+        assert_eq!(*forward.last().unwrap(), 7.0);
+    }
+}
+
 /// Generates a repeated sequence of evenly spaced numbers over a specified interval.
 ///
 /// This is useful for periodic signal generation where the same sequence is needed multiple times.
@@ -182,17 +440,25 @@ mod linspace_repeated_tests {
     }
 }
 
-/// Generates a sequence of evenly spaced numbers over a specified interval with a fixed step.
+/// Generates a sequence of evenly spaced numbers over a half-open interval `[start, stop)`
+/// with a fixed step.
 ///
-/// This is equivalent to the NumPy [`arange()`](https://numpy.org/doc/stable/reference/generated/numpy.arange.html) function.
+/// This is equivalent to the NumPy [`arange()`](https://numpy.org/doc/stable/reference/generated/numpy.arange.html)
+/// function and its half-open `[start, stop)` semantics: for integer types `stop` is never
+/// included, even when it lands exactly on a step boundary. The element count is
+/// `ceil((stop - start) / step)` computed in `f64`, so for floating-point steps rounding in
+/// that quotient can add one element at `stop` (e.g. `arange(1.0, 1.3, 0.1)` yields four
+/// values, the last equal to `1.3`). Each element is computed as
+/// `start + (k as f64) * step` cast back to `T`, the same multiply-by-index technique
+/// used by [`linspace_iter`], so floating-point steps don't accumulate rounding error.
 ///
 /// # Arguments
 /// * `start` - Start value of the sequence.
-/// * `stop` - Stop value of the sequence.
+/// * `stop` - Stop value of the sequence (exclusive).
 /// * `step` - Spacing between elements (must be nonzero).
 ///
 /// # Returns
-/// * `Vec<i32> containing the evenly spaced values.`
+/// * `Vec<T>` containing the evenly spaced values.
 ///
 /// # Panics
 /// Panics if `step == 0` to prevent infinite loops.
@@ -200,28 +466,28 @@ mod linspace_repeated_tests {
 /// # Examples
 /// ```
 /// use rusp::misc::utils::arange;
-/// let result = arange(0,10,2);
-/// assert_eq!(result, vec![0,2,4,6,8])
+///
+/// let result = arange(0, 10, 2);
+/// assert_eq!(result, vec![0, 2, 4, 6, 8]);
+///
+/// let result: Vec<f64> = arange(0.0, 2.0, 0.5);
+/// assert_eq!(result, vec![0.0, 0.5, 1.0, 1.5]);
 /// ```
-pub fn arange(start: i32, stop: i32, step: i32) -> Vec<i32> {
-    assert!(step != 0, "Step size cannot be zero");
+pub fn arange<T>(start: T, stop: T, step: T) -> Vec<T>
+where
+    T: Num + NumCast + Copy + PartialOrd,
+{
+    let start_f = start.to_f64().unwrap();
+    let stop_f = stop.to_f64().unwrap();
+    let step_f = step.to_f64().unwrap();
 
-    let mut values = Vec::new();
-    let mut current = start;
+    assert!(step_f != 0.0, "Step size cannot be zero");
 
-    if step > 0 {
-        while current < stop {
-            values.push(current);
-            current += step;
-        }
-    } else {
-        while current > stop {
-            values.push(current);
-            current += step;
-        }
-    }
+    let count = ((stop_f - start_f) / step_f).ceil().max(0.0) as usize;
 
-    values
+    (0..count)
+        .map(|k| T::from(start_f + k as f64 * step_f).unwrap())
+        .collect()
 }
 
 #[cfg(test)]
@@ -245,6 +511,39 @@ mod arange_tests {
     fn test_arange_zero_step() {
         arange(0, 10, 0);
     }
+
+    #[test]
+    fn test_arange_i64() {
+        let result: Vec<i64> = arange(0i64, 10, 2);
+        assert_eq!(result, vec![0i64, 2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn test_arange_f32() {
+        let result: Vec<f32> = arange(0.0f32, 1.0, 0.25);
+        assert_eq!(result, vec![0.0f32, 0.25, 0.5, 0.75]);
+    }
+
+    #[test]
+    fn test_arange_f64_step() {
+        let result = arange(0.0, 2.0, 0.5);
+        assert_eq!(result, vec![0.0, 0.5, 1.0, 1.5]);
+    }
+
+    #[test]
+    fn test_arange_f64_boundary_sample_not_dropped() {
+        // 0.1 is not exactly representable, so repeated addition historically
+        // either dropped or duplicated the sample nearest the boundary.
+        let result: Vec<f64> = arange(0.0, 0.3, 0.1);
+        assert_eq!(result.len(), 3);
+        assert!((result[2] - 0.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_arange_excludes_stop_on_exact_boundary() {
+        let result = arange(0, 10, 5);
+        assert_eq!(result, vec![0, 5]);
+    }
 }
 
 /// Reverses the order of elements in an array.
@@ -475,3 +774,359 @@ mod concatenate_tests {
         );
     }
 }
+
+/// Splits a complex sequence into separate real and imaginary vectors.
+///
+/// # Arguments
+/// * `input` - A slice of complex numbers.
+///
+/// # Returns
+/// * `(Vec<f64>, Vec<f64>)` - The real parts and imaginary parts, in order.
+///
+/// # Examples
+/// ```
+/// use num_complex::Complex;
+/// use rusp::misc::utils::split_complex;
+///
+/// let (re, im) = split_complex(&[Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)]);
+/// assert_eq!(re, vec![1.0, 3.0]);
+/// assert_eq!(im, vec![2.0, 4.0]);
+/// ```
+pub fn split_complex(input: &[Complex<f64>]) -> (Vec<f64>, Vec<f64>) {
+    let mut real = Vec::with_capacity(input.len());
+    let mut imag = Vec::with_capacity(input.len());
+
+    for c in input {
+        real.push(c.re);
+        imag.push(c.im);
+    }
+
+    (real, imag)
+}
+
+/// Merges separate real and imaginary vectors into a complex sequence.
+///
+/// This is the inverse of [`split_complex`].
+///
+/// # Arguments
+/// * `real` - The real parts.
+/// * `imag` - The imaginary parts.
+///
+/// # Returns
+/// * `Vec<Complex<f64>>` - The combined complex sequence.
+///
+/// # Panics
+/// Panics if `real` and `imag` have different lengths.
+///
+/// # Examples
+/// ```
+/// use num_complex::Complex;
+/// use rusp::misc::utils::merge_complex;
+///
+/// let result = merge_complex(&[1.0, 3.0], &[2.0, 4.0]);
+/// assert_eq!(result, vec![Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)]);
+/// ```
+pub fn merge_complex(real: &[f64], imag: &[f64]) -> Vec<Complex<f64>> {
+    assert_eq!(
+        real.len(),
+        imag.len(),
+        "real and imaginary parts must have the same length"
+    );
+
+    real.iter()
+        .zip(imag.iter())
+        .map(|(&re, &im)| Complex::new(re, im))
+        .collect()
+}
+
+#[cfg(test)]
+mod split_merge_complex_tests {
+    use super::{merge_complex, split_complex};
+    use num_complex::Complex;
+
+    #[test]
+    fn test_split_complex() {
+        let (re, im) = split_complex(&[Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)]);
+        assert_eq!(re, vec![1.0, 3.0]);
+        assert_eq!(im, vec![2.0, 4.0]);
+    }
+
+    #[test]
+    fn test_split_complex_empty() {
+        let (re, im) = split_complex(&[]);
+        assert!(re.is_empty());
+        assert!(im.is_empty());
+    }
+
+    #[test]
+    fn test_merge_complex() {
+        let result = merge_complex(&[1.0, 3.0], &[2.0, 4.0]);
+        assert_eq!(result, vec![Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "real and imaginary parts must have the same length")]
+    fn test_merge_complex_mismatched_lengths() {
+        merge_complex(&[1.0, 2.0], &[3.0]);
+    }
+
+    #[test]
+    fn test_split_merge_roundtrip() {
+        let input = vec![Complex::new(1.0, 2.0), Complex::new(-3.5, 4.5)];
+        let (re, im) = split_complex(&input);
+        assert_eq!(merge_complex(&re, &im), input);
+    }
+}
+
+/// Interleaves real and imaginary vectors into a single `[re0, im0, re1, im1, ...]` layout.
+///
+/// # Arguments
+/// * `real` - The real parts.
+/// * `imag` - The imaginary parts.
+///
+/// # Returns
+/// * `Vec<f64>` - The interleaved sequence.
+///
+/// # Panics
+/// Panics if `real` and `imag` have different lengths.
+///
+/// # Examples
+/// ```
+/// use rusp::misc::utils::interleave;
+///
+/// let result = interleave(&[1.0, 3.0], &[2.0, 4.0]);
+/// assert_eq!(result, vec![1.0, 2.0, 3.0, 4.0]);
+/// ```
+pub fn interleave(real: &[f64], imag: &[f64]) -> Vec<f64> {
+    assert_eq!(
+        real.len(),
+        imag.len(),
+        "real and imaginary parts must have the same length"
+    );
+
+    let mut out = Vec::with_capacity(real.len() * 2);
+    for (&re, &im) in real.iter().zip(imag.iter()) {
+        out.push(re);
+        out.push(im);
+    }
+    out
+}
+
+/// Splits an interleaved `[re0, im0, re1, im1, ...]` sequence back into
+/// separate real and imaginary vectors.
+///
+/// This is the inverse of [`interleave`].
+///
+/// # Arguments
+/// * `arr` - The interleaved sequence.
+///
+/// # Returns
+/// * `(Vec<f64>, Vec<f64>)` - The real parts and imaginary parts, in order.
+///
+/// # Panics
+/// Panics if `arr` has an odd length.
+///
+/// # Examples
+/// ```
+/// use rusp::misc::utils::deinterleave;
+///
+/// let (re, im) = deinterleave(&[1.0, 2.0, 3.0, 4.0]);
+/// assert_eq!(re, vec![1.0, 3.0]);
+/// assert_eq!(im, vec![2.0, 4.0]);
+/// ```
+pub fn deinterleave(arr: &[f64]) -> (Vec<f64>, Vec<f64>) {
+    assert_eq!(
+        arr.len() % 2,
+        0,
+        "interleaved array must have an even length"
+    );
+
+    let mut real = Vec::with_capacity(arr.len() / 2);
+    let mut imag = Vec::with_capacity(arr.len() / 2);
+    for chunk in arr.chunks_exact(2) {
+        real.push(chunk[0]);
+        imag.push(chunk[1]);
+    }
+    (real, imag)
+}
+
+#[cfg(test)]
+mod interleave_tests {
+    use super::{deinterleave, interleave};
+
+    #[test]
+    fn test_interleave() {
+        let result = interleave(&[1.0, 3.0], &[2.0, 4.0]);
+        assert_eq!(result, vec![1.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "real and imaginary parts must have the same length")]
+    fn test_interleave_mismatched_lengths() {
+        interleave(&[1.0, 2.0], &[3.0]);
+    }
+
+    #[test]
+    fn test_deinterleave() {
+        let (re, im) = deinterleave(&[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(re, vec![1.0, 3.0]);
+        assert_eq!(im, vec![2.0, 4.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "interleaved array must have an even length")]
+    fn test_deinterleave_odd_length() {
+        deinterleave(&[1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_interleave_deinterleave_roundtrip() {
+        let (real, imag) = (vec![1.0, -2.0, 3.5], vec![0.5, 2.0, -4.0]);
+        let (re, im) = deinterleave(&interleave(&real, &imag));
+        assert_eq!(re, real);
+        assert_eq!(im, imag);
+    }
+}
+
+/// Circularly shifts a slice by an arbitrary signed amount.
+///
+/// A positive `k` rotates elements to higher indices (matching NumPy's
+/// [`roll()`](https://numpy.org/doc/stable/reference/generated/numpy.roll.html)); a negative `k` rotates
+/// them to lower indices. `k` may be larger in magnitude than the slice length.
+///
+/// # Arguments
+/// * `arr` - The input slice.
+/// * `k` - The signed shift amount.
+///
+/// # Returns
+/// * `Vec<T>` - A new vector with elements circularly shifted by `k`.
+///
+/// # Examples
+/// ```
+/// use rusp::misc::utils::roll;
+///
+/// let result = roll(&[1, 2, 3, 4, 5], 2);
+/// assert_eq!(result, vec![4, 5, 1, 2, 3]);
+///
+/// let result = roll(&[1, 2, 3, 4, 5], -2);
+/// assert_eq!(result, vec![3, 4, 5, 1, 2]);
+/// ```
+pub fn roll<T: Clone>(arr: &[T], k: isize) -> Vec<T> {
+    let n = arr.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let shift = k.rem_euclid(n as isize) as usize;
+    if shift == 0 {
+        return arr.to_vec();
+    }
+
+    let mut result = Vec::with_capacity(n);
+    result.extend_from_slice(&arr[n - shift..]);
+    result.extend_from_slice(&arr[..n - shift]);
+    result
+}
+
+/// Rotates a slice so the zero-frequency (DC) component moves from index `0`
+/// to index `ceil(n / 2)`: the center for even `n`, one past the center for
+/// odd `n`.
+///
+/// Rotates left by `floor(n / 2)`. [`ifftshift`] rotates left by
+/// `ceil(n / 2)`, so the two undo each other for every `n`; for even `n` they
+/// produce the same result.
+///
+/// # Arguments
+/// * `arr` - The input slice, typically an FFT output.
+///
+/// # Returns
+/// * `Vec<T>` - A new vector with the zero-frequency component centered.
+///
+/// # Examples
+/// ```
+/// use rusp::misc::utils::fftshift;
+///
+/// let result = fftshift(&[0, 1, 2, 3, 4, 5]);
+/// assert_eq!(result, vec![3, 4, 5, 0, 1, 2]);
+/// ```
+pub fn fftshift<T: Clone>(arr: &[T]) -> Vec<T> {
+    let shift = arr.len() / 2;
+    roll(arr, -(shift as isize))
+}
+
+/// Rotates a slice so the centered zero-frequency component moves back to
+/// index `0`.
+///
+/// Rotates left by `ceil(n / 2)`. Together with the `floor(n / 2)` rotation of
+/// [`fftshift`] this makes a full turn, so the two undo each other for every `n`.
+///
+/// # Arguments
+/// * `arr` - The input slice, typically a centered spectrum.
+///
+/// # Returns
+/// * `Vec<T>` - A new vector with the zero-frequency component moved back to index `0`.
+///
+/// # Examples
+/// ```
+/// use rusp::misc::utils::ifftshift;
+///
+/// let result = ifftshift(&[3, 4, 5, 0, 1, 2]);
+/// assert_eq!(result, vec![0, 1, 2, 3, 4, 5]);
+/// ```
+pub fn ifftshift<T: Clone>(arr: &[T]) -> Vec<T> {
+    let shift = arr.len().div_ceil(2);
+    roll(arr, -(shift as isize))
+}
+
+#[cfg(test)]
+mod roll_shift_tests {
+    use super::{fftshift, ifftshift, roll};
+
+    #[test]
+    fn test_roll_positive() {
+        let result = roll(&[1, 2, 3, 4, 5], 2);
+        assert_eq!(result, vec![4, 5, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_roll_negative() {
+        let result = roll(&[1, 2, 3, 4, 5], -2);
+        assert_eq!(result, vec![3, 4, 5, 1, 2]);
+    }
+
+    #[test]
+    fn test_roll_wraps_large_magnitudes() {
+        let result = roll(&[1, 2, 3, 4, 5], 7);
+        assert_eq!(result, roll(&[1, 2, 3, 4, 5], 2));
+    }
+
+    #[test]
+    fn test_roll_zero_is_identity() {
+        let result = roll(&[1, 2, 3], 0);
+        assert_eq!(result, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_roll_empty() {
+        let result: Vec<i32> = roll(&[], 3);
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_fftshift_even() {
+        let result = fftshift(&[0, 1, 2, 3, 4, 5]);
+        assert_eq!(result, vec![3, 4, 5, 0, 1, 2]);
+    }
+
+    #[test]
+    fn test_ifftshift_even() {
+        let result = ifftshift(&[3, 4, 5, 0, 1, 2]);
+        assert_eq!(result, vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_fftshift_ifftshift_roundtrip_odd_length() {
+        let input = vec![0, 1, 2, 3, 4];
+        assert_eq!(ifftshift(&fftshift(&input)), input);
+    }
+}